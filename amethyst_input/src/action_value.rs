@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::bindings::BindingTypes;
+
+/// Tracks the current continuous value of each action, combining however many
+/// raw bindings (buttons, axes) feed into it.
+///
+/// Binary button bindings report `0.0`/`1.0`; analog bindings (stick axes,
+/// triggers) report their real magnitude. When more than one binding maps to
+/// the same action, the combined value is whichever contributing source
+/// currently has the largest magnitude, so e.g. a "move_forward" action bound
+/// to both a key and a stick axis doesn't get stuck reporting a stale value
+/// from whichever binding last changed.
+#[derive(Debug)]
+pub struct ActionValueTracker<T>
+where
+    T: BindingTypes,
+    T::Action: Clone + Eq + Hash,
+{
+    // Per-action, per-binding-source value, each already clamped to [-1.0, 1.0].
+    // The source key is caller-defined (e.g. a button or controller axis id)
+    // and only needs to be unique among the bindings feeding one action.
+    sources: HashMap<T::Action, HashMap<u32, f32>>,
+}
+
+impl<T> Default for ActionValueTracker<T>
+where
+    T: BindingTypes,
+    T::Action: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        ActionValueTracker {
+            sources: HashMap::new(),
+        }
+    }
+}
+
+impl<T> ActionValueTracker<T>
+where
+    T: BindingTypes,
+    T::Action: Clone + Eq + Hash,
+{
+    /// Creates a tracker with no actions bound yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the value reported by binding `source` for `action`, clamped
+    /// to `[-1.0, 1.0]`, and returns the action's new combined value if it
+    /// changed as a result.
+    pub fn set(&mut self, action: T::Action, source: u32, value: f32) -> Option<f32> {
+        let clamped = value.clamp(-1.0, 1.0);
+        let before = self.value(&action);
+        self.sources.entry(action.clone()).or_default().insert(source, clamped);
+        let after = self.value(&action);
+        if (before - after).abs() > f32::EPSILON {
+            Some(after)
+        } else {
+            None
+        }
+    }
+
+    /// Removes a binding source's contribution to `action`, e.g. when the key
+    /// or button backing it is released. Returns the action's new combined
+    /// value if it changed as a result.
+    pub fn clear(&mut self, action: T::Action, source: u32) -> Option<f32> {
+        let before = self.value(&action);
+        if let Some(sources) = self.sources.get_mut(&action) {
+            sources.remove(&source);
+        }
+        let after = self.value(&action);
+        if (before - after).abs() > f32::EPSILON {
+            Some(after)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the action's current combined value: the contributing source
+    /// with the largest magnitude, or `0.0` if nothing is bound or held.
+    ///
+    /// If two or more sources are tied for the largest magnitude and disagree
+    /// on sign (e.g. a "-1.0" key and a "+1.0" key both held), the result is
+    /// `0.0` rather than an arbitrary pick between them: ties are resolved
+    /// deterministically by value, never by iteration order, which a
+    /// `HashMap` does not otherwise guarantee across runs.
+    pub fn value(&self, action: &T::Action) -> f32 {
+        let Some(sources) = self.sources.get(action) else {
+            return 0.0;
+        };
+
+        let max_magnitude = sources.values().fold(0.0_f32, |acc, v| acc.max(v.abs()));
+        let mut winners = sources.values().copied().filter(|v| v.abs() == max_magnitude);
+        let Some(first) = winners.next() else {
+            return 0.0;
+        };
+        if winners.all(|v| v == first) {
+            first
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct TestBindings;
+
+    impl BindingTypes for TestBindings {
+        type Axis = String;
+        type Action = String;
+    }
+
+    #[test]
+    fn single_source_reports_its_clamped_value() {
+        let mut tracker: ActionValueTracker<TestBindings> = ActionValueTracker::new();
+        tracker.set("move".to_string(), 0, 1.5);
+        assert_eq!(tracker.value(&"move".to_string()), 1.0);
+    }
+
+    #[test]
+    fn dominant_magnitude_wins_across_sources() {
+        let mut tracker: ActionValueTracker<TestBindings> = ActionValueTracker::new();
+        tracker.set("move".to_string(), 0, 0.2);
+        tracker.set("move".to_string(), 1, -0.8);
+        assert_eq!(tracker.value(&"move".to_string()), -0.8);
+    }
+
+    #[test]
+    fn clearing_a_source_falls_back_to_the_remaining_one() {
+        let mut tracker: ActionValueTracker<TestBindings> = ActionValueTracker::new();
+        tracker.set("move".to_string(), 0, 0.2);
+        tracker.set("move".to_string(), 1, -0.8);
+        tracker.clear("move".to_string(), 1);
+        assert_eq!(tracker.value(&"move".to_string()), 0.2);
+    }
+
+    #[test]
+    fn opposing_sources_tied_on_magnitude_cancel_out_deterministically() {
+        let mut tracker: ActionValueTracker<TestBindings> = ActionValueTracker::new();
+        tracker.set("move".to_string(), 0, -1.0);
+        tracker.set("move".to_string(), 1, 1.0);
+        // Regardless of HashMap iteration order, a full-press tie in opposite
+        // directions must resolve to a stable value, not whichever source
+        // happened to be visited last.
+        for _ in 0..5 {
+            assert_eq!(tracker.value(&"move".to_string()), 0.0);
+        }
+    }
+
+    #[test]
+    fn agreeing_sources_tied_on_magnitude_keep_that_value() {
+        let mut tracker: ActionValueTracker<TestBindings> = ActionValueTracker::new();
+        tracker.set("move".to_string(), 0, 1.0);
+        tracker.set("move".to_string(), 1, 1.0);
+        assert_eq!(tracker.value(&"move".to_string()), 1.0);
+    }
+
+    #[test]
+    fn unbound_action_reports_zero() {
+        let tracker: ActionValueTracker<TestBindings> = ActionValueTracker::new();
+        assert_eq!(tracker.value(&"move".to_string()), 0.0);
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Serialize};
+
+/// An opaque identifier for a physical keyboard or mouse.
+///
+/// Sourced from winit's device-event stream, this lets callers distinguish
+/// input arriving from two keyboards or two mice plugged into the same
+/// machine, e.g. for couch co-op setups. The id is stable for as long as the
+/// device stays connected, but is not guaranteed to be stable across runs or
+/// after a disconnect/reconnect.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+pub struct DeviceId(u64);
+
+impl DeviceId {
+    /// Returns a fixed id for use by synthetic/injected events, e.g. from
+    /// `MockInput`, where there is no physical device to derive one from.
+    pub fn mock() -> Self {
+        DeviceId(0)
+    }
+}
+
+impl DeviceId {
+    // `winit::event::DeviceId` doesn't expose its inner value, and isn't
+    // itself `Serialize`/`Hash`-able in a way we can store on an event, so we
+    // derive a stable local id from its `Debug` representation. Pulled out as
+    // its own function (rather than inlined in the `From` impl) so the
+    // hashing behavior can be unit-tested against representative `Debug`
+    // output without needing a real `winit::event::DeviceId` to construct one
+    // from.
+    fn from_debug(debug: &str) -> DeviceId {
+        let mut hasher = DefaultHasher::new();
+        debug.hash(&mut hasher);
+        DeviceId(hasher.finish())
+    }
+}
+
+impl From<winit::event::DeviceId> for DeviceId {
+    fn from(id: winit::event::DeviceId) -> Self {
+        DeviceId::from_debug(&format!("{:?}", id))
+    }
+}
+
+/// Which kind of device a `DeviceId` belongs to.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+pub enum DeviceKind {
+    /// A keyboard.
+    Keyboard,
+    /// A mouse.
+    Mouse,
+}
+
+/// Tracks which keyboards and mice are currently attached.
+///
+/// The input system calls `connect` whenever a keyboard or mouse sends it
+/// input, and `disconnect` when winit's device-event stream reports the
+/// device removed. Callers building
+/// couch-co-op style setups use `keyboards`/`mice`/`is_connected` to enumerate
+/// or check a specific id without separately polling the `InputHandler`.
+#[derive(Default, Debug)]
+pub struct DeviceRegistry {
+    keyboards: HashSet<DeviceId>,
+    mice: HashSet<DeviceId>,
+}
+
+impl DeviceRegistry {
+    /// Creates an empty registry with no devices attached.
+    pub fn new() -> Self {
+        DeviceRegistry::default()
+    }
+
+    /// Marks `device` as attached.
+    pub fn connect(&mut self, kind: DeviceKind, device: DeviceId) {
+        match kind {
+            DeviceKind::Keyboard => self.keyboards.insert(device),
+            DeviceKind::Mouse => self.mice.insert(device),
+        };
+    }
+
+    /// Marks `device` as no longer attached.
+    pub fn disconnect(&mut self, device: DeviceId) {
+        self.keyboards.remove(&device);
+        self.mice.remove(&device);
+    }
+
+    /// Returns `true` if `device` is currently attached, of either kind.
+    pub fn is_connected(&self, device: DeviceId) -> bool {
+        self.keyboards.contains(&device) || self.mice.contains(&device)
+    }
+
+    /// Returns the currently attached keyboards.
+    pub fn keyboards(&self) -> impl Iterator<Item = &DeviceId> {
+        self.keyboards.iter()
+    }
+
+    /// Returns the currently attached mice.
+    pub fn mice(&self) -> impl Iterator<Item = &DeviceId> {
+        self.mice.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connect_disconnect_round_trip() {
+        let mut registry = DeviceRegistry::new();
+        let keyboard = DeviceId::from_debug("DeviceId(1)");
+        let mouse = DeviceId::from_debug("DeviceId(2)");
+
+        registry.connect(DeviceKind::Keyboard, keyboard);
+        registry.connect(DeviceKind::Mouse, mouse);
+        assert!(registry.is_connected(keyboard));
+        assert!(registry.is_connected(mouse));
+        assert_eq!(registry.keyboards().collect::<Vec<_>>(), vec![&keyboard]);
+        assert_eq!(registry.mice().collect::<Vec<_>>(), vec![&mouse]);
+
+        registry.disconnect(keyboard);
+        assert!(!registry.is_connected(keyboard));
+        assert!(registry.is_connected(mouse));
+    }
+
+    #[test]
+    fn unconnected_device_is_not_connected() {
+        let registry = DeviceRegistry::new();
+        assert!(!registry.is_connected(DeviceId::from_debug("DeviceId(99)")));
+    }
+
+    #[test]
+    fn distinct_debug_representations_hash_to_distinct_ids() {
+        // Representative `Debug` output across the backends winit targets:
+        // a bare integer id, and a platform-specific wrapped struct.
+        let samples = [
+            "DeviceId(1)",
+            "DeviceId(2)",
+            "DeviceId(X11 { id: 1 })",
+            "DeviceId(X11 { id: 2 })",
+            "DeviceId(Wayland)",
+        ];
+        let ids: Vec<DeviceId> = samples.iter().map(|s| DeviceId::from_debug(s)).collect();
+        for (i, a) in ids.iter().enumerate() {
+            for (j, b) in ids.iter().enumerate() {
+                if i != j {
+                    assert_ne!(a, b, "{:?} and {:?} collided", samples[i], samples[j]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn same_debug_representation_hashes_identically() {
+        assert_eq!(
+            DeviceId::from_debug("DeviceId(1)"),
+            DeviceId::from_debug("DeviceId(1)")
+        );
+    }
+}
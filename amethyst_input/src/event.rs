@@ -6,7 +6,10 @@ use super::{
     bindings::BindingTypes,
     button::Button,
     controller::{ControllerAxis, ControllerButton},
+    device_id::DeviceId,
+    modifiers_state::ModifiersState,
     scroll_direction::ScrollDirection,
+    scroll_unit::ScrollUnit,
 };
 
 /// Events generated by the input system
@@ -25,6 +28,10 @@ where
         key_code: VirtualKeyCode,
         /// Scancode, used for positional info. i.e. The third key on the first row was pressed.
         scancode: u32,
+        /// The keyboard modifiers held at the time of the press.
+        modifiers: ModifiersState,
+        /// The keyboard the key was pressed on.
+        device: DeviceId,
     },
     /// A key was released, sent exactly once per key release.
     KeyReleased {
@@ -32,13 +39,31 @@ where
         key_code: VirtualKeyCode,
         /// Scancode, used for positional info. i.e. The third key on the first row was released.
         scancode: u32,
+        /// The keyboard modifiers held at the time of the release.
+        modifiers: ModifiersState,
+        /// The keyboard the key was released on.
+        device: DeviceId,
     },
     /// A unicode character was received by the window.  Good for typing.
     KeyTyped(char),
     /// A mouse button was pressed down, sent exactly once per press.
-    MouseButtonPressed(MouseButton),
+    MouseButtonPressed {
+        /// The mouse button that was pressed.
+        button: MouseButton,
+        /// The keyboard modifiers held at the time of the press.
+        modifiers: ModifiersState,
+        /// The mouse the button was pressed on.
+        device: DeviceId,
+    },
     /// A mouse button was released, sent exactly once per release.
-    MouseButtonReleased(MouseButton),
+    MouseButtonReleased {
+        /// The mouse button that was released.
+        button: MouseButton,
+        /// The keyboard modifiers held at the time of the release.
+        modifiers: ModifiersState,
+        /// The mouse the button was released on.
+        device: DeviceId,
+    },
     /// A button was pressed.
     ButtonPressed(Button),
     /// A button was released.
@@ -49,6 +74,10 @@ where
         delta_x: f64,
         /// The amount the cursor moved vertically in pixels.
         delta_y: f64,
+        /// The keyboard modifiers held while the cursor moved.
+        modifiers: ModifiersState,
+        /// The mouse that moved the cursor.
+        device: DeviceId,
     },
     /// The mouse device moved. Use this for any use of the mouse that doesn't involve a standard
     /// mouse pointer.
@@ -57,9 +86,27 @@ where
         delta_x: f32,
         /// The amount the mouse moved vertically.
         delta_y: f32,
+        /// The keyboard modifiers held while the mouse moved.
+        modifiers: ModifiersState,
+        /// The physical mouse device this relative motion was read from.
+        device: DeviceId,
     },
     /// The mousewheel was moved in either direction
     MouseWheelMoved(ScrollDirection),
+    /// The mousewheel was scrolled by a precise amount.
+    ///
+    /// Unlike `MouseWheelMoved`, this carries the signed delta reported by the
+    /// device, so smooth-scrolling UIs and zoom controls can use the real
+    /// magnitude instead of a bare direction. `unit` distinguishes discrete
+    /// tick wheels from trackpads and other precision-scrolling devices.
+    MouseWheelScrolled {
+        /// Whether `delta_x`/`delta_y` are in lines or pixels.
+        unit: ScrollUnit,
+        /// The horizontal scroll delta.
+        delta_x: f64,
+        /// The vertical scroll delta.
+        delta_y: f64,
+    },
     /// An axis value changed.
     ///
     /// Note that this variant is used for `BindingTypes::Axis`, not a `ControllerAxis`.
@@ -116,4 +163,70 @@ where
     ActionReleased(T::Action),
     /// The associated action has its mouse wheel moved.
     ActionWheelMoved(T::Action),
+    /// The associated action's continuous value changed.
+    ///
+    /// Unlike `ActionPressed`/`ActionReleased`, this reports a clamped analog
+    /// value rather than a binary state, so actions bound to an analog source
+    /// (a trigger, a stick axis) can report e.g. `0.37` instead of collapsing
+    /// to "pressed". Actions bound only to digital buttons still emit this
+    /// with `0.0`/`1.0`. The combined value across multiple bindings on one
+    /// action (the dominant magnitude) is computed by `ActionValueTracker`,
+    /// which the input system consults before emitting this event.
+    ActionValueChanged {
+        /// The action whose value changed.
+        action: T::Action,
+        /// The new clamped value of the action, in `[-1.0, 1.0]` for axis-like
+        /// actions or `[0.0, 1.0]` for button-like ones.
+        value: f32,
+    },
+    /// A mouse button was pressed and released again without the cursor
+    /// leaving the configured slop radius, synthesized by the gesture
+    /// recognizer from the raw `MouseButtonPressed`/`MouseButtonReleased`/
+    /// `CursorMoved` stream.
+    Click {
+        /// The button that was clicked.
+        button: MouseButton,
+        /// The horizontal position of the click.
+        x: f64,
+        /// The vertical position of the click.
+        y: f64,
+    },
+    /// A second qualifying `Click` landed within the configured interval and
+    /// slop radius of the first, and is reported instead of a second `Click`.
+    DoubleClick {
+        /// The button that was double-clicked.
+        button: MouseButton,
+        /// The horizontal position of the double-click.
+        x: f64,
+        /// The vertical position of the double-click.
+        y: f64,
+    },
+    /// A mouse button is held and the cursor has moved past the configured
+    /// slop radius since the press. Emitted every frame the cursor moves
+    /// until the button is released.
+    Drag {
+        /// The button being held during the drag.
+        button: MouseButton,
+        /// The cursor position where the drag started.
+        start: (f64, f64),
+        /// The current cursor position.
+        current: (f64, f64),
+        /// The change in cursor position since the last `Drag` event, or
+        /// since the press for the first `Drag` of a hold.
+        delta: (f64, f64),
+    },
+    /// The associated action has its mouse wheel scrolled by a precise amount.
+    ///
+    /// The action-level equivalent of `MouseWheelScrolled`, for actions bound
+    /// to wheel input that want the real magnitude rather than a direction.
+    ActionWheelScrolled {
+        /// The action whose wheel binding scrolled.
+        action: T::Action,
+        /// Whether `delta_x`/`delta_y` are in lines or pixels.
+        unit: ScrollUnit,
+        /// The horizontal scroll delta.
+        delta_x: f64,
+        /// The vertical scroll delta.
+        delta_y: f64,
+    },
 }
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use winit::event::MouseButton;
+
+use super::{bindings::BindingTypes, event::InputEvent};
+
+/// Configuration for the pointer gesture recognizer, set on the `InputBundle`.
+#[derive(Clone, Copy, Debug)]
+pub struct GestureConfig {
+    /// The maximum distance, in pixels, the cursor may move between press and
+    /// release for it to still count as a click rather than a drag.
+    pub slop_radius: f64,
+    /// The maximum time between two clicks for the second one to be folded
+    /// into a `DoubleClick`.
+    pub double_click_interval: Duration,
+}
+
+impl Default for GestureConfig {
+    fn default() -> Self {
+        GestureConfig {
+            slop_radius: 4.0,
+            double_click_interval: Duration::from_millis(400),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ButtonState {
+    press_pos: (f64, f64),
+    last_pos: (f64, f64),
+    dragging: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct LastClick {
+    pos: (f64, f64),
+    at: Instant,
+}
+
+/// Derives higher-level `Click`/`DoubleClick`/`Drag` events from the raw
+/// `MouseButtonPressed`/`MouseButtonReleased`/`CursorMoved` stream.
+///
+/// Raw events keep flowing through the input system unchanged; this only adds
+/// synthesized `InputEvent`s alongside them, so existing consumers of the raw
+/// stream are unaffected.
+#[derive(Debug)]
+pub struct GestureRecognizer {
+    config: GestureConfig,
+    held: HashMap<MouseButton, ButtonState>,
+    last_click: HashMap<MouseButton, LastClick>,
+}
+
+impl GestureRecognizer {
+    /// Creates a recognizer using the given configuration.
+    pub fn new(config: GestureConfig) -> Self {
+        GestureRecognizer {
+            config,
+            held: HashMap::new(),
+            last_click: HashMap::new(),
+        }
+    }
+
+    fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+        ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+    }
+
+    /// Call with the current cursor position whenever a button is pressed.
+    pub fn press(&mut self, button: MouseButton, pos: (f64, f64)) {
+        self.held.insert(
+            button,
+            ButtonState {
+                press_pos: pos,
+                last_pos: pos,
+                dragging: false,
+            },
+        );
+    }
+
+    /// Call with the current cursor position whenever the cursor moves while
+    /// a button may be held. Returns a `Drag` event if the button is held and
+    /// past the slop radius.
+    pub fn cursor_moved<T: BindingTypes>(
+        &mut self,
+        pos: (f64, f64),
+    ) -> Vec<InputEvent<T>> {
+        let mut events = Vec::new();
+        for (&button, state) in self.held.iter_mut() {
+            if state.dragging || Self::distance(state.press_pos, pos) > self.config.slop_radius {
+                state.dragging = true;
+                let delta = (pos.0 - state.last_pos.0, pos.1 - state.last_pos.1);
+                state.last_pos = pos;
+                events.push(InputEvent::Drag {
+                    button,
+                    start: state.press_pos,
+                    current: pos,
+                    delta,
+                });
+            }
+        }
+        events
+    }
+
+    /// Call with the current cursor position and time whenever a button is
+    /// released. Returns a `Click` or `DoubleClick` event if the release
+    /// qualifies, or `None` if it ended a drag or landed past the slop radius
+    /// from the press position.
+    pub fn release<T: BindingTypes>(
+        &mut self,
+        button: MouseButton,
+        pos: (f64, f64),
+        now: Instant,
+    ) -> Option<InputEvent<T>> {
+        let state = self.held.remove(&button)?;
+        if state.dragging || Self::distance(state.press_pos, pos) > self.config.slop_radius {
+            return None;
+        }
+
+        let is_double = self
+            .last_click
+            .get(&button)
+            .map(|last| {
+                now.duration_since(last.at) <= self.config.double_click_interval
+                    && Self::distance(last.pos, pos) <= self.config.slop_radius
+            })
+            .unwrap_or(false);
+
+        if is_double {
+            self.last_click.remove(&button);
+            Some(InputEvent::DoubleClick {
+                button,
+                x: pos.0,
+                y: pos.1,
+            })
+        } else {
+            self.last_click.insert(button, LastClick { pos, at: now });
+            Some(InputEvent::Click {
+                button,
+                x: pos.0,
+                y: pos.1,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct TestBindings;
+
+    impl BindingTypes for TestBindings {
+        type Axis = String;
+        type Action = String;
+    }
+
+    fn recognizer() -> GestureRecognizer {
+        GestureRecognizer::new(GestureConfig {
+            slop_radius: 4.0,
+            double_click_interval: Duration::from_millis(400),
+        })
+    }
+
+    #[test]
+    fn cursor_at_exact_slop_radius_does_not_drag() {
+        let mut g = recognizer();
+        g.press(MouseButton::Left, (0.0, 0.0));
+        let drags = g.cursor_moved::<TestBindings>((4.0, 0.0));
+        assert!(drags.is_empty());
+    }
+
+    #[test]
+    fn cursor_past_slop_radius_drags() {
+        let mut g = recognizer();
+        g.press(MouseButton::Left, (0.0, 0.0));
+        let drags = g.cursor_moved::<TestBindings>((4.0001, 0.0));
+        assert_eq!(drags.len(), 1);
+        assert!(matches!(drags[0], InputEvent::Drag { .. }));
+    }
+
+    #[test]
+    fn release_within_slop_radius_is_a_click() {
+        let mut g = recognizer();
+        g.press(MouseButton::Left, (0.0, 0.0));
+        let event = g.release::<TestBindings>(MouseButton::Left, (4.0, 0.0), Instant::now());
+        assert!(matches!(event, Some(InputEvent::Click { .. })));
+    }
+
+    #[test]
+    fn dragging_past_slop_radius_suppresses_click_on_release() {
+        let mut g = recognizer();
+        g.press(MouseButton::Left, (0.0, 0.0));
+        g.cursor_moved::<TestBindings>((5.0, 0.0));
+        let event = g.release::<TestBindings>(MouseButton::Left, (5.0, 0.0), Instant::now());
+        assert!(event.is_none());
+    }
+
+    #[test]
+    fn second_click_within_interval_and_radius_is_a_double_click() {
+        let mut g = recognizer();
+        let t0 = Instant::now();
+        g.press(MouseButton::Left, (0.0, 0.0));
+        g.release::<TestBindings>(MouseButton::Left, (0.0, 0.0), t0);
+        g.press(MouseButton::Left, (1.0, 0.0));
+        let event =
+            g.release::<TestBindings>(MouseButton::Left, (1.0, 0.0), t0 + Duration::from_millis(400));
+        assert!(matches!(event, Some(InputEvent::DoubleClick { .. })));
+    }
+
+    #[test]
+    fn second_click_past_interval_is_a_plain_click() {
+        let mut g = recognizer();
+        let t0 = Instant::now();
+        g.press(MouseButton::Left, (0.0, 0.0));
+        g.release::<TestBindings>(MouseButton::Left, (0.0, 0.0), t0);
+        g.press(MouseButton::Left, (0.0, 0.0));
+        let event =
+            g.release::<TestBindings>(MouseButton::Left, (0.0, 0.0), t0 + Duration::from_millis(401));
+        assert!(matches!(event, Some(InputEvent::Click { .. })));
+    }
+
+    #[test]
+    fn second_click_past_slop_radius_is_a_plain_click() {
+        let mut g = recognizer();
+        let t0 = Instant::now();
+        g.press(MouseButton::Left, (0.0, 0.0));
+        g.release::<TestBindings>(MouseButton::Left, (0.0, 0.0), t0);
+        g.press(MouseButton::Left, (10.0, 0.0));
+        let event =
+            g.release::<TestBindings>(MouseButton::Left, (10.0, 0.0), t0 + Duration::from_millis(100));
+        assert!(matches!(event, Some(InputEvent::Click { .. })));
+    }
+}
@@ -0,0 +1,160 @@
+use std::hash::Hash;
+
+use super::{
+    bindings::BindingTypes, button::Button, controller::ControllerButton, device_id::DeviceId,
+    event::InputEvent, modifiers_state::ModifiersState, system::InputProcessor,
+};
+
+/// Synthesizes `InputEvent`s and routes them through `InputProcessor::process_raw`,
+/// the same bookkeeping real winit-sourced events go through, so tests and
+/// replay/demo tooling can drive gameplay without a real device.
+///
+/// Injecting a `ControllerButtonPressed` through `inject` fires the
+/// corresponding `ActionPressed`/`ActionValueChanged` exactly like a real
+/// press would, as long as the binding was registered with
+/// `bind_controller_button`. The raw event is always recorded too, so
+/// consumers of the raw stream are unaffected.
+#[derive(Debug, Default)]
+pub struct MockInput<T>
+where
+    T: BindingTypes,
+    T::Action: Clone + Eq + Hash,
+{
+    processor: InputProcessor<T>,
+    /// Every event `inject` has produced so far, in order, standing in for the
+    /// real input system's event channel.
+    pub events: Vec<InputEvent<T>>,
+}
+
+impl<T> MockInput<T>
+where
+    T: BindingTypes,
+    T::Action: Clone + Eq + Hash,
+{
+    /// Creates an empty mock input source with no bindings registered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `button` as bound to `action`, so injecting it fires the
+    /// matching `ActionPressed`/`ActionReleased`/`ActionValueChanged`.
+    pub fn bind_button(&mut self, button: Button, action: T::Action) {
+        self.processor.bind_button(button, action);
+    }
+
+    /// Registers a controller button as bound to `action`, so injecting it
+    /// fires the matching `ActionPressed`/`ActionReleased`/`ActionValueChanged`.
+    pub fn bind_controller_button(&mut self, which: u32, button: ControllerButton, action: T::Action) {
+        self.processor.bind_controller_button(which, button, action);
+    }
+
+    /// Returns whether `button` is currently held, per the injected events.
+    pub fn is_button_pressed(&self, button: Button) -> bool {
+        self.processor.is_button_pressed(button)
+    }
+
+    /// Injects `event`, updating pressed-button sets and action state exactly
+    /// as the real input system would for the equivalent winit event, and
+    /// records it (plus any derived `Action*` events) onto `self.events`.
+    pub fn inject(&mut self, event: InputEvent<T>) {
+        let produced = self.processor.process_raw(event);
+        self.events.extend(produced);
+    }
+
+    /// Builds a button-press event, for use with `inject`.
+    pub fn press_button(button: Button) -> InputEvent<T> {
+        InputEvent::ButtonPressed(button)
+    }
+
+    /// Builds a button-release event, for use with `inject`.
+    pub fn release_button(button: Button) -> InputEvent<T> {
+        InputEvent::ButtonReleased(button)
+    }
+
+    /// Builds an axis-moved event for `axis` reporting `value`, for use with
+    /// `inject`.
+    pub fn move_axis(axis: T::Axis, value: f64) -> InputEvent<T> {
+        InputEvent::AxisMoved { axis, value }
+    }
+
+    /// Builds a cursor-moved event reporting the delta from `from` to `to`,
+    /// for use with `inject`.
+    pub fn move_cursor(from: (f64, f64), to: (f64, f64)) -> InputEvent<T> {
+        InputEvent::CursorMoved {
+            delta_x: to.0 - from.0,
+            delta_y: to.1 - from.1,
+            modifiers: ModifiersState::empty(),
+            device: DeviceId::mock(),
+        }
+    }
+
+    /// Builds a controller-button-press event, for use with `inject`.
+    pub fn controller_button_pressed(which: u32, button: ControllerButton) -> InputEvent<T> {
+        InputEvent::ControllerButtonPressed { which, button }
+    }
+
+    /// Builds a controller-button-release event, for use with `inject`.
+    pub fn controller_button_released(which: u32, button: ControllerButton) -> InputEvent<T> {
+        InputEvent::ControllerButtonReleased { which, button }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct TestBindings;
+
+    impl BindingTypes for TestBindings {
+        type Axis = String;
+        type Action = String;
+    }
+
+    #[test]
+    fn bound_controller_button_press_fires_action_pressed() {
+        let mut mock: MockInput<TestBindings> = MockInput::new();
+        mock.bind_controller_button(0, ControllerButton::South, "jump".to_string());
+
+        mock.inject(MockInput::controller_button_pressed(0, ControllerButton::South));
+
+        assert!(mock
+            .events
+            .iter()
+            .any(|e| matches!(e, InputEvent::ActionPressed(action) if action == "jump")));
+        assert!(mock.events.iter().any(|e| matches!(
+            e,
+            InputEvent::ActionValueChanged { action, value } if action == "jump" && *value == 1.0
+        )));
+        assert!(mock
+            .events
+            .iter()
+            .any(|e| matches!(e, InputEvent::ControllerButtonPressed { which: 0, button: ControllerButton::South })));
+    }
+
+    #[test]
+    fn unbound_controller_button_press_fires_no_action() {
+        let mut mock: MockInput<TestBindings> = MockInput::new();
+
+        mock.inject(MockInput::controller_button_pressed(0, ControllerButton::South));
+
+        assert!(!mock
+            .events
+            .iter()
+            .any(|e| matches!(e, InputEvent::ActionPressed(_) | InputEvent::ActionValueChanged { .. })));
+    }
+
+    #[test]
+    fn bound_controller_button_release_fires_action_released() {
+        let mut mock: MockInput<TestBindings> = MockInput::new();
+        mock.bind_controller_button(0, ControllerButton::South, "jump".to_string());
+        mock.inject(MockInput::controller_button_pressed(0, ControllerButton::South));
+
+        mock.inject(MockInput::controller_button_released(0, ControllerButton::South));
+
+        assert!(mock
+            .events
+            .iter()
+            .any(|e| matches!(e, InputEvent::ActionReleased(action) if action == "jump")));
+    }
+}
@@ -0,0 +1,38 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+
+bitflags! {
+    /// A snapshot of which keyboard modifier keys are currently held down.
+    ///
+    /// The input system keeps a cached copy of this, updated from winit's
+    /// `ModifiersChanged` event, and stamps it onto every `InputEvent` that
+    /// carries key or mouse state. This keeps emitted events consistent even
+    /// when the modifier change and the key/mouse event it applies to arrive
+    /// in different frames.
+    ///
+    /// `winit::event::ModifiersState` only reports these four keys without
+    /// distinguishing which physical key (left/right) triggered them, so
+    /// that's all this type can track until winit exposes more.
+    #[derive(Default, Serialize, Deserialize)]
+    pub struct ModifiersState: u8 {
+        /// Either shift key is held.
+        const SHIFT = 0b0000_0001;
+        /// Either control key is held.
+        const CTRL = 0b0000_0010;
+        /// Either alt key is held.
+        const ALT = 0b0000_0100;
+        /// Either logo key (Windows/Command/Super) is held.
+        const LOGO = 0b0000_1000;
+    }
+}
+
+impl From<winit::event::ModifiersState> for ModifiersState {
+    fn from(state: winit::event::ModifiersState) -> Self {
+        let mut result = ModifiersState::empty();
+        result.set(ModifiersState::SHIFT, state.shift());
+        result.set(ModifiersState::CTRL, state.ctrl());
+        result.set(ModifiersState::ALT, state.alt());
+        result.set(ModifiersState::LOGO, state.logo());
+        result
+    }
+}
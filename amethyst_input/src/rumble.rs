@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// A dual-motor vibration effect targeting a connected controller.
+///
+/// Mirrors the low/high frequency motor split most gamepads expose: the low
+/// frequency motor gives a heavier rumble, the high frequency motor a
+/// sharper buzz.
+#[derive(Clone, Copy, Debug)]
+pub struct RumbleCommand {
+    /// The id of the controller to rumble, matching `ControllerConnected::which`.
+    pub which: u32,
+    /// Low frequency (heavy) motor strength, `0.0` to `1.0`.
+    pub low_frequency: f32,
+    /// High frequency (sharp) motor strength, `0.0` to `1.0`.
+    pub high_frequency: f32,
+    /// How long the effect should run before stopping on its own.
+    pub duration: Duration,
+}
+
+/// A controller backend capable of applying rumble commands to real
+/// hardware, e.g. an SDL2 or gilrs haptics handle for a connected gamepad.
+///
+/// The default `apply` is a no-op, so backends or individual devices without
+/// haptics support can simply not override it and `RumbleOutput` still drives
+/// them without special-casing.
+pub trait RumbleBackend {
+    /// Applies `command` to whichever physical controller it targets.
+    fn apply(&mut self, command: RumbleCommand) {
+        let _ = command;
+    }
+}
+
+struct ActiveRumble {
+    command: RumbleCommand,
+    started_at: Instant,
+}
+
+impl ActiveRumble {
+    fn remaining(&self, now: Instant) -> Duration {
+        self.command
+            .duration
+            .checked_sub(now.duration_since(self.started_at))
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
+/// Tracks active rumble effects per controller and exposes the haptics
+/// output side of the controller subsystem.
+///
+/// Backends or devices without haptics support simply never see their
+/// effects applied; `set_rumble` and `update` still no-op cleanly in that
+/// case.
+#[derive(Default)]
+pub struct RumbleOutput {
+    active: HashMap<u32, ActiveRumble>,
+}
+
+impl RumbleOutput {
+    /// Creates an empty tracker with no active effects.
+    pub fn new() -> Self {
+        RumbleOutput {
+            active: HashMap::new(),
+        }
+    }
+
+    /// Starts a rumble effect on controller `which`. If an effect is already
+    /// playing on that controller, the two are layered: the stronger motor
+    /// strength on each side wins, and the effect keeps running for however
+    /// long the longer-lived of the two has left.
+    pub fn set_rumble(&mut self, which: u32, low_freq: f32, high_freq: f32, duration: Duration) {
+        let low_freq = low_freq.clamp(0.0, 1.0);
+        let high_freq = high_freq.clamp(0.0, 1.0);
+        let now = Instant::now();
+
+        let (low_frequency, high_frequency, duration) = match self.active.get(&which) {
+            Some(existing) => (
+                existing.command.low_frequency.max(low_freq),
+                existing.command.high_frequency.max(high_freq),
+                existing.remaining(now).max(duration),
+            ),
+            None => (low_freq, high_freq, duration),
+        };
+
+        self.active.insert(
+            which,
+            ActiveRumble {
+                command: RumbleCommand {
+                    which,
+                    low_frequency,
+                    high_frequency,
+                    duration,
+                },
+                started_at: now,
+            },
+        );
+    }
+
+    /// Starts a rumble effect on controller `which`, unconditionally replacing
+    /// whatever effect is already playing on it instead of layering with it.
+    pub fn replace_rumble(&mut self, which: u32, low_freq: f32, high_freq: f32, duration: Duration) {
+        self.active.insert(
+            which,
+            ActiveRumble {
+                command: RumbleCommand {
+                    which,
+                    low_frequency: low_freq.clamp(0.0, 1.0),
+                    high_frequency: high_freq.clamp(0.0, 1.0),
+                    duration,
+                },
+                started_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Immediately stops any rumble effect on controller `which`, e.g. when it
+    /// disconnects.
+    pub fn stop(&mut self, which: u32) {
+        self.active.remove(&which);
+    }
+
+    /// Drops effects whose duration has elapsed and returns the commands that
+    /// are still active, for the controller backend to apply this frame.
+    pub fn update(&mut self) -> impl Iterator<Item = RumbleCommand> + '_ {
+        let now = Instant::now();
+        self.active
+            .retain(|_, rumble| now.duration_since(rumble.started_at) < rumble.command.duration);
+        self.active.values().map(|rumble| rumble.command)
+    }
+
+    /// Drops expired effects and applies every still-active one to `backend`.
+    /// The call site for this is the controller subsystem's per-frame update;
+    /// backends without haptics support can use `RumbleBackend`'s default
+    /// no-op `apply` and this still runs cleanly.
+    pub fn drive(&mut self, backend: &mut impl RumbleBackend) {
+        for command in self.update() {
+            backend.apply(command);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn active_effect_is_returned_before_it_expires() {
+        let mut output = RumbleOutput::new();
+        output.set_rumble(1, 0.5, 0.25, Duration::from_millis(50));
+        let commands: Vec<_> = output.update().collect();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].which, 1);
+    }
+
+    #[test]
+    fn expired_effect_is_dropped_on_update() {
+        let mut output = RumbleOutput::new();
+        output.set_rumble(1, 0.5, 0.25, Duration::from_millis(0));
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(output.update().count(), 0);
+    }
+
+    #[test]
+    fn stop_removes_the_effect_immediately() {
+        let mut output = RumbleOutput::new();
+        output.set_rumble(1, 1.0, 1.0, Duration::from_secs(5));
+        output.stop(1);
+        assert_eq!(output.update().count(), 0);
+    }
+
+    #[test]
+    fn set_rumble_layers_with_the_stronger_motor_strengths() {
+        let mut output = RumbleOutput::new();
+        output.set_rumble(1, 0.2, 0.8, Duration::from_secs(5));
+        output.set_rumble(1, 0.6, 0.1, Duration::from_secs(5));
+        let commands: Vec<_> = output.update().collect();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].low_frequency, 0.6);
+        assert_eq!(commands[0].high_frequency, 0.8);
+    }
+
+    #[test]
+    fn drive_applies_active_commands_and_skips_expired_ones() {
+        struct Recorder(Vec<RumbleCommand>);
+        impl RumbleBackend for Recorder {
+            fn apply(&mut self, command: RumbleCommand) {
+                self.0.push(command);
+            }
+        }
+
+        let mut output = RumbleOutput::new();
+        output.set_rumble(1, 1.0, 1.0, Duration::from_secs(5));
+        output.set_rumble(2, 1.0, 1.0, Duration::from_millis(0));
+        std::thread::sleep(Duration::from_millis(5));
+
+        let mut backend = Recorder(Vec::new());
+        output.drive(&mut backend);
+
+        assert_eq!(backend.0.len(), 1);
+        assert_eq!(backend.0[0].which, 1);
+    }
+
+    #[test]
+    fn drive_no_ops_with_the_default_backend_impl() {
+        struct Silent;
+        impl RumbleBackend for Silent {}
+
+        let mut output = RumbleOutput::new();
+        output.set_rumble(1, 1.0, 1.0, Duration::from_secs(5));
+        // Exercises the default `apply` no-op; this should not panic.
+        output.drive(&mut Silent);
+    }
+
+    #[test]
+    fn replace_rumble_discards_the_previous_effect() {
+        let mut output = RumbleOutput::new();
+        output.set_rumble(1, 1.0, 1.0, Duration::from_secs(5));
+        output.replace_rumble(1, 0.1, 0.1, Duration::from_secs(5));
+        let commands: Vec<_> = output.update().collect();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].low_frequency, 0.1);
+        assert_eq!(commands[0].high_frequency, 0.1);
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// The unit a scroll delta is reported in.
+///
+/// Tick wheels (most desktop mice) report whole "lines" per notch, while
+/// trackpads and high-resolution wheels report continuous pixel deltas. The
+/// input system maps winit's `MouseScrollDelta::LineDelta` to `Line` and
+/// `MouseScrollDelta::PixelDelta` to `Pixel`.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize, Debug)]
+pub enum ScrollUnit {
+    /// The delta is measured in discrete lines/notches.
+    Line,
+    /// The delta is measured in pixels.
+    Pixel,
+}
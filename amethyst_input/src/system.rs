@@ -0,0 +1,331 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use winit::event::{DeviceEvent, ElementState, MouseScrollDelta, WindowEvent};
+
+use super::{
+    action_value::ActionValueTracker,
+    bindings::BindingTypes,
+    button::Button,
+    controller::ControllerButton,
+    device_id::{DeviceId, DeviceKind, DeviceRegistry},
+    event::InputEvent,
+    modifiers_state::ModifiersState,
+    rumble::{RumbleBackend, RumbleOutput},
+    scroll_unit::ScrollUnit,
+};
+
+fn binding_source_id<H: Hash>(value: &H) -> u32 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish() as u32
+}
+
+/// Turns winit events into `InputEvent`s.
+///
+/// This holds the cross-frame state the individual event/helper types in this
+/// crate need a home for but can't carry themselves: the currently-held
+/// keyboard modifiers, attached devices, pressed buttons, and per-action
+/// values. `process_window_event` is the only way real winit events reach an
+/// `InputEvent`, and it ends by calling `process_raw`, the single place
+/// pressed-button sets and action state are updated and `Action*` events are
+/// derived from bindings. `process_device_event` produces no events; it only
+/// updates the device registry. `MockInput` calls
+/// the very same `process_raw`, so injected events are indistinguishable from
+/// winit-sourced ones once they reach it.
+pub struct InputProcessor<T>
+where
+    T: BindingTypes,
+    T::Action: Clone + Eq + Hash,
+{
+    modifiers: ModifiersState,
+    last_cursor_pos: HashMap<DeviceId, (f64, f64)>,
+    devices: DeviceRegistry,
+    pressed_buttons: HashSet<Button>,
+    pressed_controller_buttons: HashSet<(u32, ControllerButton)>,
+    button_bindings: HashMap<Button, T::Action>,
+    controller_bindings: HashMap<(u32, ControllerButton), T::Action>,
+    values: ActionValueTracker<T>,
+    rumble: RumbleOutput,
+}
+
+impl<T> Default for InputProcessor<T>
+where
+    T: BindingTypes,
+    T::Action: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        InputProcessor {
+            modifiers: ModifiersState::empty(),
+            last_cursor_pos: HashMap::new(),
+            devices: DeviceRegistry::new(),
+            pressed_buttons: HashSet::new(),
+            pressed_controller_buttons: HashSet::new(),
+            button_bindings: HashMap::new(),
+            controller_bindings: HashMap::new(),
+            values: ActionValueTracker::new(),
+            rumble: RumbleOutput::new(),
+        }
+    }
+}
+
+impl<T> InputProcessor<T>
+where
+    T: BindingTypes,
+    T::Action: Clone + Eq + Hash,
+{
+    /// Creates a processor with no modifiers held.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The keyboard modifiers currently cached from the last `ModifiersChanged`
+    /// event, stamped onto every subsequently-produced key/mouse event so
+    /// readers see a consistent snapshot even if the modifier and key events
+    /// arrive in different frames.
+    pub fn modifiers(&self) -> ModifiersState {
+        self.modifiers
+    }
+
+    /// The keyboards and mice currently known to be attached.
+    pub fn devices(&self) -> &DeviceRegistry {
+        &self.devices
+    }
+
+    /// Registers `button` as bound to `action`, so a `ButtonPressed`/
+    /// `ButtonReleased` for it fires the matching `ActionPressed`/
+    /// `ActionReleased`/`ActionValueChanged` once it reaches `process_raw`.
+    pub fn bind_button(&mut self, button: Button, action: T::Action) {
+        self.button_bindings.insert(button, action);
+    }
+
+    /// Registers a controller button as bound to `action`, so a
+    /// `ControllerButtonPressed`/`ControllerButtonReleased` for it fires the
+    /// matching `ActionPressed`/`ActionReleased`/`ActionValueChanged` once it
+    /// reaches `process_raw`.
+    pub fn bind_controller_button(&mut self, which: u32, button: ControllerButton, action: T::Action) {
+        self.controller_bindings.insert((which, button), action);
+    }
+
+    /// Returns whether `button` is currently held.
+    pub fn is_button_pressed(&self, button: Button) -> bool {
+        self.pressed_buttons.contains(&button)
+    }
+
+    /// Starts (or layers onto) a rumble effect on controller `which`. See
+    /// `RumbleOutput::set_rumble`.
+    pub fn set_rumble(
+        &mut self,
+        which: u32,
+        low_freq: f32,
+        high_freq: f32,
+        duration: std::time::Duration,
+    ) {
+        self.rumble.set_rumble(which, low_freq, high_freq, duration);
+    }
+
+    /// Drops expired rumble effects and applies every still-active one to
+    /// `backend`. Call this once per frame from the controller subsystem's
+    /// update; backends without haptics support can rely on
+    /// `RumbleBackend`'s default no-op `apply`.
+    pub fn drive_rumble(&mut self, backend: &mut impl RumbleBackend) {
+        self.rumble.drive(backend);
+    }
+
+    /// Feeds a single winit `WindowEvent` in, returning the `InputEvent`s it
+    /// produces (zero or more). Every constructed event is run through
+    /// `process_raw`, the same path injected events take. Keyboard and mouse
+    /// input comes out as `KeyPressed`/`MouseButtonPressed` and the like only;
+    /// it is not turned into `ButtonPressed`/`ButtonReleased`, so it does not
+    /// change the pressed-button set or fire button-bound actions.
+    pub fn process_window_event(&mut self, event: &WindowEvent<'_>) -> Vec<InputEvent<T>> {
+        match event {
+            WindowEvent::ModifiersChanged(state) => {
+                self.modifiers = (*state).into();
+                Vec::new()
+            }
+            WindowEvent::KeyboardInput {
+                device_id, input, ..
+            } => {
+                let device = DeviceId::from(*device_id);
+                self.devices.connect(DeviceKind::Keyboard, device);
+                let Some(key_code) = input.virtual_keycode else {
+                    return Vec::new();
+                };
+                let event = match input.state {
+                    ElementState::Pressed => InputEvent::KeyPressed {
+                        key_code,
+                        scancode: input.scancode,
+                        modifiers: self.modifiers,
+                        device,
+                    },
+                    ElementState::Released => InputEvent::KeyReleased {
+                        key_code,
+                        scancode: input.scancode,
+                        modifiers: self.modifiers,
+                        device,
+                    },
+                };
+                self.process_raw(event)
+            }
+            WindowEvent::MouseInput {
+                device_id,
+                state,
+                button,
+                ..
+            } => {
+                let device = DeviceId::from(*device_id);
+                self.devices.connect(DeviceKind::Mouse, device);
+                let event = match state {
+                    ElementState::Pressed => InputEvent::MouseButtonPressed {
+                        button: *button,
+                        modifiers: self.modifiers,
+                        device,
+                    },
+                    ElementState::Released => InputEvent::MouseButtonReleased {
+                        button: *button,
+                        modifiers: self.modifiers,
+                        device,
+                    },
+                };
+                self.process_raw(event)
+            }
+            WindowEvent::CursorMoved {
+                device_id,
+                position,
+                ..
+            } => {
+                let device = DeviceId::from(*device_id);
+                self.devices.connect(DeviceKind::Mouse, device);
+                let pos = (position.x, position.y);
+                let (prev_x, prev_y) = self.last_cursor_pos.insert(device, pos).unwrap_or(pos);
+                self.process_raw(InputEvent::CursorMoved {
+                    delta_x: pos.0 - prev_x,
+                    delta_y: pos.1 - prev_y,
+                    modifiers: self.modifiers,
+                    device,
+                })
+            }
+            // `scroll_direction.rs` (the legacy `MouseWheelMoved(ScrollDirection)`
+            // producer) isn't part of this tree slice, so only the new
+            // precision-delta path is wired up here.
+            WindowEvent::MouseWheel { delta, .. } => {
+                let (unit, delta_x, delta_y) = match delta {
+                    MouseScrollDelta::LineDelta(x, y) => {
+                        (ScrollUnit::Line, f64::from(*x), f64::from(*y))
+                    }
+                    MouseScrollDelta::PixelDelta(pos) => (ScrollUnit::Pixel, pos.x, pos.y),
+                };
+                self.process_raw(InputEvent::MouseWheelScrolled {
+                    unit,
+                    delta_x,
+                    delta_y,
+                })
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Runs a single already-constructed `InputEvent` through pressed-button
+    /// and action-state bookkeeping, returning it alongside any `Action*`
+    /// events it derives from a registered binding. This is the one place
+    /// that bookkeeping happens: both real winit-sourced events (via
+    /// `process_window_event`) and injected ones (via `MockInput::inject`)
+    /// call it, so there is exactly one processing path for either.
+    pub fn process_raw(&mut self, event: InputEvent<T>) -> Vec<InputEvent<T>> {
+        let mut events = Vec::new();
+        match &event {
+            InputEvent::ButtonPressed(button) => {
+                self.pressed_buttons.insert(*button);
+                if let Some(action) = self.button_bindings.get(button).cloned() {
+                    events.push(InputEvent::ActionPressed(action.clone()));
+                    if let Some(value) = self.values.set(action.clone(), binding_source_id(button), 1.0) {
+                        events.push(InputEvent::ActionValueChanged { action, value });
+                    }
+                }
+            }
+            InputEvent::ButtonReleased(button) => {
+                self.pressed_buttons.remove(button);
+                if let Some(action) = self.button_bindings.get(button).cloned() {
+                    events.push(InputEvent::ActionReleased(action.clone()));
+                    if let Some(value) = self.values.clear(action.clone(), binding_source_id(button)) {
+                        events.push(InputEvent::ActionValueChanged { action, value });
+                    }
+                }
+            }
+            InputEvent::ControllerButtonPressed { which, button } => {
+                self.pressed_controller_buttons.insert((*which, *button));
+                if let Some(action) = self.controller_bindings.get(&(*which, *button)).cloned() {
+                    events.push(InputEvent::ActionPressed(action.clone()));
+                    let src = binding_source_id(&(*which, *button));
+                    if let Some(value) = self.values.set(action.clone(), src, 1.0) {
+                        events.push(InputEvent::ActionValueChanged { action, value });
+                    }
+                }
+            }
+            InputEvent::ControllerButtonReleased { which, button } => {
+                self.pressed_controller_buttons.remove(&(*which, *button));
+                if let Some(action) = self.controller_bindings.get(&(*which, *button)).cloned() {
+                    events.push(InputEvent::ActionReleased(action.clone()));
+                    let src = binding_source_id(&(*which, *button));
+                    if let Some(value) = self.values.clear(action.clone(), src) {
+                        events.push(InputEvent::ActionValueChanged { action, value });
+                    }
+                }
+            }
+            InputEvent::ControllerDisconnected { which } => {
+                // A disconnected controller can't feel a rumble it was mid-effect
+                // on, and `which` may be reused by a different physical pad.
+                self.rumble.stop(*which);
+            }
+            _ => {}
+        }
+        events.push(event);
+        events
+    }
+
+    /// Feeds a single winit `DeviceEvent` in. Currently only used to notice
+    /// when a keyboard or mouse disconnects; the id may be reused by a
+    /// different physical device afterwards.
+    pub fn process_device_event(&mut self, device_id: winit::event::DeviceId, event: &DeviceEvent) {
+        if let DeviceEvent::Removed = event {
+            let device = DeviceId::from(device_id);
+            self.devices.disconnect(device);
+            self.last_cursor_pos.remove(&device);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rumble::RumbleCommand;
+
+    #[derive(Default)]
+    struct TestBindings;
+
+    impl BindingTypes for TestBindings {
+        type Axis = String;
+        type Action = String;
+    }
+
+    struct Recorder(Vec<RumbleCommand>);
+    impl RumbleBackend for Recorder {
+        fn apply(&mut self, command: RumbleCommand) {
+            self.0.push(command);
+        }
+    }
+
+    #[test]
+    fn controller_disconnect_stops_its_rumble() {
+        let mut processor: InputProcessor<TestBindings> = InputProcessor::new();
+        processor.set_rumble(1, 1.0, 1.0, std::time::Duration::from_secs(5));
+
+        processor.process_raw(InputEvent::ControllerDisconnected { which: 1 });
+
+        let mut backend = Recorder(Vec::new());
+        processor.drive_rumble(&mut backend);
+        assert!(backend.0.is_empty());
+    }
+}